@@ -0,0 +1,130 @@
+use rand::Rng;
+use sdl2::rect::Point;
+use specs::prelude::*;
+
+use crate::components::*;
+use crate::qlearning::{Action, LastDecision, QTable, CONTACT_DISTANCE, TRAINING_EPSILON};
+use crate::rng::GameRng;
+
+const RANDOM_MOVE_CHANCE: u32 = 10;
+const CHASE_SPEED: i32 = 3;
+
+/// Which controller `AI` runs this tick, selected once at startup from the CLI.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AiMode {
+	/// Moves in a random direction 1-in-`RANDOM_MOVE_CHANCE` ticks, otherwise stops.
+	Random,
+	/// Chases the player greedily from the `QTable`; explores with `TRAINING_EPSILON`
+	/// while `training` so the table keeps improving.
+	QLearning {training: bool},
+}
+
+impl Default for AiMode {
+	fn default() -> Self {
+		AiMode::Random
+	}
+}
+
+pub struct AI;
+
+impl<'a> System<'a> for AI {
+	type SystemData = (
+		Entities<'a>,
+		ReadStorage<'a, Enemy>,
+		ReadStorage<'a, KeyboardControlled>,
+		ReadStorage<'a, Position>,
+		WriteStorage<'a, Velocity>,
+		WriteStorage<'a, LastDecision>,
+		Write<'a, QTable>,
+		Write<'a, GameRng>,
+		Read<'a, AiMode>,
+	);
+
+	fn run(&mut self, (entities, enemies, players, positions, mut velocities, mut decisions, mut qtable, mut rng, mode): Self::SystemData) {
+		match *mode {
+			AiMode::Random => run_random(&entities, &enemies, &mut velocities, &mut rng),
+			AiMode::QLearning {training} => run_qlearning(
+				&entities, &enemies, &players, &positions, &mut velocities, &mut decisions, &mut qtable, &mut rng, training,
+			),
+		}
+	}
+}
+
+fn run_random<'a>(
+	entities: &Entities<'a>,
+	enemies: &ReadStorage<'a, Enemy>,
+	velocities: &mut WriteStorage<'a, Velocity>,
+	rng: &mut GameRng,
+) {
+	let rng = &mut rng.0;
+
+	for (_, _, vel) in (entities, enemies, velocities).join() {
+		if rng.gen_range(0, RANDOM_MOVE_CHANCE) == 0 {
+			let directions = [Direction::Up, Direction::Down, Direction::Left, Direction::Right];
+			vel.direction = directions[rng.gen_range(0, directions.len())];
+			vel.speed = CHASE_SPEED;
+		} else {
+			vel.speed = 0;
+		}
+	}
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_qlearning<'a>(
+	entities: &Entities<'a>,
+	enemies: &ReadStorage<'a, Enemy>,
+	players: &ReadStorage<'a, KeyboardControlled>,
+	positions: &ReadStorage<'a, Position>,
+	velocities: &mut WriteStorage<'a, Velocity>,
+	decisions: &mut WriteStorage<'a, LastDecision>,
+	qtable: &mut QTable,
+	rng: &mut GameRng,
+	training: bool,
+) {
+	let player_position = (positions, players).join().map(|(pos, _)| pos.0).next();
+	let player_position = match player_position {
+		Some(position) => position,
+		None => return,
+	};
+
+	let epsilon = if training {TRAINING_EPSILON} else {0.0};
+	let rng = &mut rng.0;
+
+	for (entity, _, pos, vel) in (entities, enemies, positions, velocities).join() {
+		let state = crate::qlearning::State::relative(pos.0, player_position);
+		let distance = manhattan_distance(pos.0, player_position);
+
+		if let Some((prev_state, prev_action, prev_distance)) = decisions.get(entity).and_then(|d| d.0) {
+			qtable.update(prev_state, prev_action, reward_for(prev_distance, distance), state);
+		}
+
+		let action = if rng.gen::<f32>() < epsilon {
+			Action::ALL[rng.gen_range(0, Action::ALL.len())]
+		} else {
+			qtable.best_action(state)
+		};
+
+		match action {
+			Action::Stop => vel.speed = 0,
+			Action::Move(direction) => {
+				vel.direction = direction;
+				vel.speed = CHASE_SPEED;
+			},
+		}
+
+		decisions.insert(entity, LastDecision(Some((state, action, distance))))
+			.expect("enemy entity is alive for the duration of this join");
+	}
+}
+
+fn manhattan_distance(a: Point, b: Point) -> i32 {
+	(a.x() - b.x()).abs() + (a.y() - b.y()).abs()
+}
+
+fn reward_for(prev_distance: i32, distance: i32) -> f32 {
+	if distance <= CONTACT_DISTANCE {
+		10.0
+	} else {
+		(prev_distance - distance) as f32 * 0.1
+	}
+}
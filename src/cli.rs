@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
+
+#[derive(Parser, Debug)]
+#[command(name = "game tutorial", about = "SDL2 + specs game tutorial")]
+pub struct Cli {
+	#[command(subcommand)]
+	pub mode: Mode,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Mode {
+	/// Open the SDL2 window and play
+	Play {
+		#[arg(long, value_enum, default_value_t = Agent::Random)]
+		agent: Agent,
+		/// Q-table to load for the `q-learning` agent; starts empty if omitted
+		#[arg(long)]
+		qtable: Option<PathBuf>,
+		/// Seeds the deterministic RNG; omit for a randomized seed each run
+		#[arg(long)]
+		seed: Option<u64>,
+	},
+	/// Run headless episodes against the q-learning agent and save its table
+	Train {
+		#[arg(long, default_value_t = 1000)]
+		episodes: u32,
+		#[arg(long, default_value = "qtable.json")]
+		output: PathBuf,
+		#[arg(long, default_value_t = 0)]
+		seed: u64,
+	},
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum Agent {
+	Random,
+	QLearning,
+}
@@ -0,0 +1,81 @@
+use specs::prelude::*;
+use sdl2::rect::Rect;
+
+use crate::components::*;
+use crate::sound::{SoundId, SoundQueue};
+
+const ENEMY_CONTACT_DAMAGE: i32 = 10;
+const KNOCKBACK_SPEED: i32 = 12;
+
+/// Tests player/enemy colliders for AABB overlap, applies contact damage and
+/// knockback to the player, and despawns the player once its `Health` runs
+/// out. Runs after `Physics` so it sees this step's updated positions.
+pub struct Collision;
+
+impl<'a> System<'a> for Collision {
+	type SystemData = (
+		Entities<'a>,
+		ReadStorage<'a, Position>,
+		ReadStorage<'a, Collider>,
+		ReadStorage<'a, KeyboardControlled>,
+		ReadStorage<'a, Enemy>,
+		WriteStorage<'a, Health>,
+		WriteStorage<'a, Velocity>,
+		Write<'a, SoundQueue>,
+	);
+
+	fn run(&mut self, (entities, positions, colliders, players, enemies, mut healths, mut velocities, mut sounds): Self::SystemData) {
+		let player = (&entities, &positions, &colliders, &players).join()
+			.map(|(entity, pos, collider, _)| (entity, bounds(pos, collider)))
+			.next();
+
+		let (player_entity, player_rect) = match player {
+			Some(player) => player,
+			None => return,
+		};
+
+		for (enemy_entity, pos, collider, _) in (&entities, &positions, &colliders, &enemies).join() {
+			let enemy_rect = bounds(pos, collider);
+
+			if !player_rect.has_intersection(enemy_rect) {
+				continue;
+			}
+
+			sounds.0.push(SoundId::Impact);
+
+			if let Some(health) = healths.get_mut(player_entity) {
+				health.current -= ENEMY_CONTACT_DAMAGE;
+			}
+
+			if let Some(vel) = velocities.get_mut(player_entity) {
+				vel.direction = knockback_direction(player_rect, enemy_rect);
+				vel.speed = KNOCKBACK_SPEED;
+			}
+
+			if healths.get(player_entity).map_or(false, |health| health.current <= 0) {
+				entities.delete(player_entity).expect("player entity already removed");
+			}
+		}
+	}
+}
+
+fn bounds(pos: &Position, collider: &Collider) -> Rect {
+	let relative = collider.0;
+	Rect::new(
+		pos.0.x() + relative.x(),
+		pos.0.y() + relative.y(),
+		relative.width(),
+		relative.height(),
+	)
+}
+
+fn knockback_direction(player_rect: Rect, enemy_rect: Rect) -> Direction {
+	let dx = player_rect.center().x() - enemy_rect.center().x();
+	let dy = player_rect.center().y() - enemy_rect.center().y();
+
+	if dx.abs() > dy.abs() {
+		if dx > 0 { Direction::Right } else { Direction::Left }
+	} else {
+		if dy > 0 { Direction::Down } else { Direction::Up }
+	}
+}
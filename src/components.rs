@@ -0,0 +1,65 @@
+use specs::prelude::*;
+use specs_derive::Component;
+use sdl2::rect::{Point, Rect};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Direction {
+	Up,
+	Down,
+	Left,
+	Right,
+}
+
+#[derive(Component, Debug)]
+#[storage(NullStorage)]
+#[derive(Default)]
+pub struct KeyboardControlled;
+
+#[derive(Component, Debug)]
+#[storage(NullStorage)]
+#[derive(Default)]
+pub struct Enemy;
+
+#[derive(Component, Debug)]
+#[storage(VecStorage)]
+pub struct Position(pub Point);
+
+#[derive(Component, Debug, Clone, Copy)]
+#[storage(VecStorage)]
+pub struct Velocity {
+	pub speed: i32,
+	pub direction: Direction,
+}
+
+#[derive(Component, Debug, Clone)]
+#[storage(VecStorage)]
+pub struct Sprite {
+	pub spritesheet: usize,
+	pub region: Rect,
+}
+
+#[derive(Component, Debug)]
+#[storage(NullStorage)]
+#[derive(Default)]
+pub struct MovingObject;
+
+#[derive(Component, Debug, Clone, Copy)]
+#[storage(VecStorage)]
+pub struct Collider(pub Rect);
+
+#[derive(Component, Debug, Clone, Copy)]
+#[storage(VecStorage)]
+pub struct Health {
+	pub current: i32,
+	pub max: i32,
+}
+
+#[derive(Component)]
+#[storage(VecStorage)]
+pub struct MovementAnimation {
+	pub current_frame: usize,
+	pub up_frames: Vec<Sprite>,
+	pub down_frames: Vec<Sprite>,
+	pub left_frames: Vec<Sprite>,
+	pub right_frames: Vec<Sprite>,
+}
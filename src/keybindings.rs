@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use sdl2::keyboard::Keycode;
+use serde::Deserialize;
+
+use crate::components::Direction;
+use crate::MovementCommand;
+
+pub type Keybindings = HashMap<Keycode, MovementCommand>;
+
+#[derive(Debug, Deserialize)]
+struct BindingsConfig {
+	bindings: Vec<KeyBinding>,
+}
+
+#[derive(Debug, Deserialize)]
+struct KeyBinding {
+	key: String,
+	action: Action,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Action {
+	MoveUp,
+	MoveDown,
+	MoveLeft,
+	MoveRight,
+	Stop,
+}
+
+impl From<Action> for MovementCommand {
+	fn from(action: Action) -> Self {
+		match action {
+			Action::MoveUp => MovementCommand::Move(Direction::Up),
+			Action::MoveDown => MovementCommand::Move(Direction::Down),
+			Action::MoveLeft => MovementCommand::Move(Direction::Left),
+			Action::MoveRight => MovementCommand::Move(Direction::Right),
+			Action::Stop => MovementCommand::Stop,
+		}
+	}
+}
+
+/// Loads key-to-command bindings from a TOML config file, falling back to
+/// the built-in arrow-key defaults if the file is missing or malformed.
+pub fn load<P: AsRef<Path>>(path: P) -> Keybindings {
+	fs::read_to_string(path)
+		.ok()
+		.and_then(|contents| parse(&contents))
+		.unwrap_or_else(default_bindings)
+}
+
+/// Parses TOML bindings config text, returning `None` if it doesn't match
+/// `BindingsConfig` (unknown key names are silently dropped, not an error).
+fn parse(contents: &str) -> Option<Keybindings> {
+	toml::from_str::<BindingsConfig>(contents).ok().map(from_config)
+}
+
+fn from_config(config: BindingsConfig) -> Keybindings {
+	config.bindings.into_iter()
+		.filter_map(|binding| Keycode::from_name(&binding.key).map(|key| (key, binding.action.into())))
+		.collect()
+}
+
+fn default_bindings() -> Keybindings {
+	let mut bindings = HashMap::new();
+	bindings.insert(Keycode::Left, MovementCommand::Move(Direction::Left));
+	bindings.insert(Keycode::Right, MovementCommand::Move(Direction::Right));
+	bindings.insert(Keycode::Up, MovementCommand::Move(Direction::Up));
+	bindings.insert(Keycode::Down, MovementCommand::Move(Direction::Down));
+	bindings
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_valid_bindings() {
+		let contents = r#"
+			[[bindings]]
+			key = "W"
+			action = "move_up"
+
+			[[bindings]]
+			key = "S"
+			action = "stop"
+		"#;
+
+		let bindings = parse(contents).expect("valid TOML should parse");
+		assert_eq!(bindings.get(&Keycode::W), Some(&MovementCommand::Move(Direction::Up)));
+		assert_eq!(bindings.get(&Keycode::S), Some(&MovementCommand::Stop));
+	}
+
+	#[test]
+	fn drops_bindings_with_unknown_key_names() {
+		let contents = r#"
+			[[bindings]]
+			key = "NotAKey"
+			action = "move_up"
+		"#;
+
+		let bindings = parse(contents).expect("valid TOML should parse");
+		assert!(bindings.is_empty());
+	}
+
+	#[test]
+	fn malformed_toml_does_not_parse() {
+		assert!(parse("this is not valid toml [[[").is_none());
+	}
+}
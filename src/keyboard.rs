@@ -0,0 +1,43 @@
+use specs::prelude::*;
+
+use crate::components::*;
+use crate::sound::{SoundId, SoundQueue};
+use crate::MovementCommand;
+
+pub struct Keyboard;
+
+impl<'a> System<'a> for Keyboard {
+	type SystemData = (
+		Write<'a, Option<MovementCommand>>,
+		ReadStorage<'a, KeyboardControlled>,
+		WriteStorage<'a, Velocity>,
+		Write<'a, SoundQueue>,
+	);
+
+	// Takes the command rather than just reading it, so a key press is applied
+	// once and doesn't overwrite, every tick afterward, velocity that other
+	// systems set (e.g. a collision's knockback). Key repeats are ignored by
+	// `Gameplay::handle_event`, so a held key is not re-sent; the velocity
+	// from the press stays until a release sends `Stop`.
+	fn run(&mut self, mut data: Self::SystemData) {
+		let movement_command = match data.0.take() {
+			Some(movement_command) => movement_command,
+			None => return,
+		};
+
+		for (_, vel) in (&data.1, &mut data.2).join() {
+			match &movement_command {
+				MovementCommand::Move(direction) => {
+					if vel.speed == 0 {
+						data.3.0.push(SoundId::Footstep);
+					}
+					vel.speed = 5;
+					vel.direction = *direction;
+				},
+				MovementCommand::Stop => {
+					vel.speed = 0;
+				},
+			}
+		}
+	}
+}
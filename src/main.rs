@@ -1,22 +1,49 @@
 mod components;
 mod physics;
+mod collision;
 mod animator;
 mod keyboard;
+mod keybindings;
 mod renderer;
 mod ai;
+mod qlearning;
+mod rng;
+mod sound;
+mod cli;
+mod scene;
+mod scenes;
+
+use clap::Parser;
 
 use sdl2::event::Event;
-use sdl2::keyboard::Keycode;
-use sdl2::pixels::Color;
 use sdl2::rect::{Point, Rect};
 use sdl2::image::{self, LoadTexture, InitFlag};
 
 use specs::prelude::*;
 
-use std::time::Duration;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
 use crate::components::*;
+use crate::scene::SceneStack;
+
+/// Fixed simulation step: physics always advances by this much real time,
+/// no matter how long a render frame takes.
+const DT: f32 = 1.0 / 60.0;
+
+/// Caps how much real elapsed time a single frame feeds the accumulator, so
+/// a slow frame (hitch, stall) can't force enough catch-up steps to make the
+/// next frame slow too (spiral of death).
+const MAX_FRAME_TIME: f32 = 0.25;
+
+/// Ticks simulated per training episode before resetting entity positions.
+const TRAINING_TICKS_PER_EPISODE: u32 = 200;
 
+/// Indices into the `textures` array passed to `renderer::render`.
+const PLAYER_SPRITESHEET: usize = 0;
+const ENEMY_SPRITESHEET: usize = 1;
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum MovementCommand {
 	Stop,
 	Move(Direction),
@@ -70,8 +97,11 @@ fn initialize_player(world: &mut World, player_spritesheet: usize) {
 
     world.create_entity()
       .with(KeyboardControlled)
+      .with(MovingObject)
       .with(Position(Point::new(0, 0)))
       .with(Velocity {speed: 0, direction: Direction::Right})
+      .with(Collider(Rect::new(-13, -18, 26, 36)))
+      .with(Health {current: 100, max: 100})
       .with(player_animation.right_frames[0].clone())
       .with(player_animation)
       .build();
@@ -90,18 +120,85 @@ fn initialize_enemy(world: &mut World, enemy_spritesheet: usize, position: Point
 
 	world.create_entity()
 	  .with(Enemy)
+		.with(MovingObject)
 		.with(Position(position))
 		.with(Velocity {speed: 0, direction: Direction::Right})
+		.with(Collider(Rect::new(-16, -18, 32, 36)))
 		.with(enemy_animation.right_frames[0].clone())
 		.with(enemy_animation)
 		.build();
 }
 
 
+/// Spawns the player and the three starting enemies into `world`. Called once
+/// when `run_game` sets up the window, and again by `Gameplay::new` whenever
+/// it finds no player entity (e.g. resuming after a death).
+pub(crate) fn spawn_player_and_enemies(world: &mut World) {
+	initialize_player(world, PLAYER_SPRITESHEET);
+	initialize_enemy(world, ENEMY_SPRITESHEET, Point::new(-150, -150));
+	initialize_enemy(world, ENEMY_SPRITESHEET, Point::new(150, -190));
+	initialize_enemy(world, ENEMY_SPRITESHEET, Point::new(-150, 170));
+}
+
 fn main() -> Result<(), String> {
+	match cli::Cli::parse().mode {
+		cli::Mode::Play {agent, qtable, seed} => run_game(agent, qtable, seed),
+		cli::Mode::Train {episodes, output, seed} => run_training(episodes, output, seed),
+	}
+}
+
+/// Seeds the RNG from `seed` if given, otherwise from the current time so
+/// unseeded runs still vary from one invocation to the next.
+fn resolve_seed(seed: Option<u64>) -> u64 {
+	seed.unwrap_or_else(|| {
+		use std::time::{SystemTime, UNIX_EPOCH};
+		SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos() as u64
+	})
+}
+
+/// Runs `episodes` headless training rounds (no window, no render) so the
+/// Q-learning agent can rack up many fast ticks, then saves its table.
+fn run_training(episodes: u32, output: PathBuf, seed: u64) -> Result<(), String> {
+	let mut dispatcher = DispatcherBuilder::new()
+		.with(ai::AI, "AI", &[])
+		.with(physics::Physics, "Physics", &["AI"])
+		.build();
+
+	let mut world = World::new();
+	dispatcher.setup(&mut world.res);
+	world.add_resource(ai::AiMode::QLearning {training: true});
+	world.add_resource(qlearning::QTable::default());
+	world.add_resource(rng::GameRng::seeded(seed));
+
+	for episode in 0..episodes {
+		world.delete_all();
+
+		initialize_player(&mut world, PLAYER_SPRITESHEET);
+		initialize_enemy(&mut world, ENEMY_SPRITESHEET, Point::new(-150, -150));
+
+		for _ in 0..TRAINING_TICKS_PER_EPISODE {
+			dispatcher.dispatch(&mut world.res);
+			world.maintain();
+		}
+
+		if episode % 100 == 0 {
+			println!("training episode {}/{}", episode, episodes);
+		}
+	}
+
+	let qtable = world.read_resource::<qlearning::QTable>();
+	qtable.save(&output).map_err(|e| e.to_string())?;
+	println!("saved {} Q-table entries to {}", qtable.0.len(), output.display());
+
+	Ok(())
+}
+
+/// Opens the SDL2 window and runs the normal game loop with the chosen agent.
+fn run_game(agent: cli::Agent, qtable_path: Option<PathBuf>, seed: Option<u64>) -> Result<(), String> {
 	let sdl_context = sdl2::init()?;
 	let video_subsystem = sdl_context.video()?;
 	let _image_context = image::init(InitFlag::PNG | InitFlag::JPG)?;
+	let _mixer_context = sound::init()?;
 
 	let window = video_subsystem.window("game tutorial", 800, 600)
 		.position_centered()
@@ -112,82 +209,111 @@ fn main() -> Result<(), String> {
 		.expect("could not make a canvas");
 
 	let texture_creator = canvas.texture_creator();
-	
-	let mut dispatcher = DispatcherBuilder::new()
-	  .with(keyboard::Keyboard, "Keyboard", &[])
-    .with(ai::AI, "AI", &[])
-    .with(physics::Physics, "Physics", &["Keyboard", "AI"])
-    .with(animator::Animator, "Animator", &["Keyboard", "AI"])
-		.build();
 
 	let mut world = World::new();
-	dispatcher.setup(&mut world.res);
 	renderer::SystemData::setup(&mut world.res);
-	
-  // Initialize resource
+
+  // Initialize resources
   let movement_command: Option<MovementCommand> = None;
   world.add_resource(movement_command);
-	
+  world.add_resource(keybindings::load("config/keybindings.toml"));
+  world.add_resource(sound::load_bank()?);
+
+  let ai_mode = match agent {
+    cli::Agent::Random => ai::AiMode::Random,
+    cli::Agent::QLearning => ai::AiMode::QLearning {training: false},
+  };
+  world.add_resource(ai_mode);
+  world.add_resource(rng::GameRng::seeded(resolve_seed(seed)));
+
+  let qtable = qtable_path
+    .map(|path| qlearning::QTable::load(&path).unwrap_or_default())
+    .unwrap_or_default();
+  world.add_resource(qtable);
+
 	let textures = [
 		texture_creator.load_texture("assets/bardo.png")?,
 		texture_creator.load_texture("assets/reaper.png")?,
 	];
-	// First texture in textures array
-	let player_spritesheet = 0;
-	let enemy_spritesheet = 1;
-	
-	initialize_player(&mut world, player_spritesheet);
+	spawn_player_and_enemies(&mut world);
 
-  initialize_enemy(&mut world, enemy_spritesheet, Point::new(-150, -150));
-  initialize_enemy(&mut world, enemy_spritesheet, Point::new(150, -190));
-  initialize_enemy(&mut world, enemy_spritesheet, Point::new(-150, 170));
+	let mut scene_stack = SceneStack::new(Box::new(scenes::Menu));
 
 	let mut event_pump = sdl_context.event_pump()?;
-	let mut i = 0;
+	let mut last_frame = Instant::now();
+	let mut accumulator = 0.0;
 	'running: loop {
-		let mut movement_command = None;
 		// Handle events
 		for event in event_pump.poll_iter() {
-			match event {
-				Event::Quit {..} |
-				Event::KeyDown { keycode: Some(Keycode::Escape), .. } => {
-					break 'running;
-				},
-				Event::KeyDown { keycode: Some(Keycode::Left), repeat: false, .. } => {
-					movement_command = Some(MovementCommand::Move(Direction::Left));
-				},
-				Event::KeyDown { keycode: Some(Keycode::Right), repeat: false, .. } => {
-					movement_command = Some(MovementCommand::Move(Direction::Right));
-				},
-				Event::KeyDown { keycode: Some(Keycode::Up), repeat: false, .. } => {
-					movement_command = Some(MovementCommand::Move(Direction::Up));
-				},
-				Event::KeyDown { keycode: Some(Keycode::Down), repeat: false, .. } => {
-					movement_command = Some(MovementCommand::Move(Direction::Down));
-				},
-				Event::KeyUp { keycode: Some(Keycode::Left), repeat: false, .. } |
-				Event::KeyUp { keycode: Some(Keycode::Right), repeat: false, .. } |
-				Event::KeyUp { keycode: Some(Keycode::Up), repeat: false, .. } |
-				Event::KeyUp { keycode: Some(Keycode::Down), repeat: false, .. } => {
-					movement_command = Some(MovementCommand::Stop);
-				},
-				_ => {}
+			if let Event::Quit {..} = event {
+				break 'running;
+			}
+			if !scene_stack.handle_event(&mut world, &event) {
+				break 'running;
 			}
 		}
-		
-		*world.write_resource() = movement_command;
-		
-		// Update
-		i = (i + 1) % 255;
-    dispatcher.dispatch(&mut world.res);
-    world.maintain();
-
-		// Render
-		renderer::render(&mut canvas, Color::RGB(i, 64, 255 - i), &textures, world.system_data())?;
-
-		// Time management
+
+		// Update, in fixed DT steps, carrying any leftover time into the next frame
+		let now = Instant::now();
+		accumulator += (now - last_frame).as_secs_f32().min(MAX_FRAME_TIME);
+		last_frame = now;
+
+		while accumulator >= DT {
+			if !scene_stack.update(&mut world) {
+				break 'running;
+			}
+			accumulator -= DT;
+		}
+
+		// Render the whole scene stack bottom-to-top, then flip once per frame
+		scene_stack.render(&world, &mut canvas, &textures)?;
+		canvas.present();
+
+		// Sleep a fixed 1/20 s every frame; time already spent this frame is not subtracted
 		::std::thread::sleep(Duration::new(0, 1_000_000_000u32 / 20));
 	}
 
 	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Builds the same headless (no SDL, no rendering) dispatcher `run_training`
+	/// uses, seeds its `GameRng`, spawns a player and one enemy, and runs it for
+	/// `ticks` steps. Since `GameRng` is the only source of randomness `AI` and
+	/// `Physics` read, the resulting positions are a pure function of `seed`.
+	fn run_n_ticks(seed: u64, ticks: u32) -> Vec<Point> {
+		let mut dispatcher = DispatcherBuilder::new()
+			.with(ai::AI, "AI", &[])
+			.with(physics::Physics, "Physics", &["AI"])
+			.build();
+
+		let mut world = World::new();
+		dispatcher.setup(&mut world.res);
+		world.add_resource(ai::AiMode::QLearning {training: true});
+		world.add_resource(qlearning::QTable::default());
+		world.add_resource(rng::GameRng::seeded(seed));
+
+		initialize_player(&mut world, PLAYER_SPRITESHEET);
+		initialize_enemy(&mut world, ENEMY_SPRITESHEET, Point::new(-150, -150));
+
+		for _ in 0..ticks {
+			dispatcher.dispatch(&mut world.res);
+			world.maintain();
+		}
+
+		world.read_storage::<Position>().join().map(|pos| pos.0).collect()
+	}
+
+	#[test]
+	fn same_seed_reproduces_positions() {
+		assert_eq!(run_n_ticks(42, 50), run_n_ticks(42, 50));
+	}
+
+	#[test]
+	fn different_seeds_produce_different_positions() {
+		assert_ne!(run_n_ticks(1, 50), run_n_ticks(2, 50));
+	}
 }
\ No newline at end of file
@@ -0,0 +1,169 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+use sdl2::rect::Point;
+use serde::{Deserialize, Serialize};
+use specs::prelude::*;
+use specs_derive::Component;
+
+use crate::components::Direction;
+
+pub const ALPHA: f32 = 0.1;
+pub const GAMMA: f32 = 0.9;
+pub const TRAINING_EPSILON: f32 = 0.1;
+pub const CONTACT_DISTANCE: i32 = 24;
+
+const DISTANCE_BUCKET: i32 = 32;
+
+/// Relative position of the player from an enemy's point of view, discretized
+/// so nearby positions collapse onto the same Q-table entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct State {
+	dx_sign: i8,
+	dx_bucket: u8,
+	dy_sign: i8,
+	dy_bucket: u8,
+}
+
+impl State {
+	pub fn relative(enemy: Point, player: Point) -> Self {
+		let dx = player.x() - enemy.x();
+		let dy = player.y() - enemy.y();
+
+		State {
+			dx_sign: dx.signum() as i8,
+			dx_bucket: (dx.abs() / DISTANCE_BUCKET).min(u8::MAX as i32) as u8,
+			dy_sign: dy.signum() as i8,
+			dy_bucket: (dy.abs() / DISTANCE_BUCKET).min(u8::MAX as i32) as u8,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Action {
+	Stop,
+	Move(Direction),
+}
+
+impl Action {
+	pub const ALL: [Action; 5] = [
+		Action::Stop,
+		Action::Move(Direction::Up),
+		Action::Move(Direction::Down),
+		Action::Move(Direction::Left),
+		Action::Move(Direction::Right),
+	];
+}
+
+/// Remembers the `(state, action)` an enemy last took so the following tick
+/// can compute a reward and apply the Bellman update for it.
+#[derive(Component, Debug, Default)]
+#[storage(VecStorage)]
+pub struct LastDecision(pub Option<(State, Action, i32)>);
+
+#[derive(Debug, Default)]
+pub struct QTable(pub HashMap<(State, Action), f32>);
+
+#[derive(Serialize, Deserialize)]
+struct QTableEntry {
+	state: State,
+	action: Action,
+	value: f32,
+}
+
+impl QTable {
+	pub fn value(&self, state: State, action: Action) -> f32 {
+		*self.0.get(&(state, action)).unwrap_or(&0.0)
+	}
+
+	// A loaded `QTable` can come from a hand-edited or otherwise corrupted
+	// `--qtable` file, so a non-finite value must not panic `partial_cmp`;
+	// treat it as tied rather than failing the whole process.
+	pub fn best_action(&self, state: State) -> Action {
+		Action::ALL.iter().copied()
+			.max_by(|a, b| self.value(state, *a).partial_cmp(&self.value(state, *b)).unwrap_or(Ordering::Equal))
+			.expect("Action::ALL is non-empty")
+	}
+
+	/// `Q[s,a] += alpha * (reward + gamma * max_a' Q[s',a'] - Q[s,a])`
+	pub fn update(&mut self, state: State, action: Action, reward: f32, next_state: State) {
+		let best_next = Action::ALL.iter().copied()
+			.map(|a| self.value(next_state, a))
+			.fold(f32::MIN, f32::max);
+
+		let current = self.value(state, action);
+		let updated = current + ALPHA * (reward + GAMMA * best_next - current);
+		self.0.insert((state, action), updated);
+	}
+
+	pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn Error>> {
+		let entries: Vec<QTableEntry> = serde_json::from_reader(BufReader::new(File::open(path)?))?;
+		Ok(QTable(entries.into_iter().map(|entry| ((entry.state, entry.action), entry.value)).collect()))
+	}
+
+	pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn Error>> {
+		let entries: Vec<QTableEntry> = self.0.iter()
+			.map(|(&(state, action), &value)| QTableEntry {state, action, value})
+			.collect();
+		serde_json::to_writer(BufWriter::new(File::create(path)?), &entries)?;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn relative_state_buckets_by_sign_and_distance() {
+		let enemy = Point::new(0, 0);
+		let player = Point::new(40, -70);
+
+		let state = State::relative(enemy, player);
+		assert_eq!(state.dx_sign, 1);
+		assert_eq!(state.dx_bucket, 40 / DISTANCE_BUCKET);
+		assert_eq!(state.dy_sign, -1);
+		assert_eq!(state.dy_bucket, 70 / DISTANCE_BUCKET);
+	}
+
+	#[test]
+	fn relative_state_has_zero_sign_when_aligned() {
+		let state = State::relative(Point::new(0, 0), Point::new(0, 0));
+		assert_eq!(state.dx_sign, 0);
+		assert_eq!(state.dy_sign, 0);
+	}
+
+	#[test]
+	fn unvisited_state_actions_default_to_zero() {
+		let table = QTable::default();
+		let state = State::relative(Point::new(0, 0), Point::new(100, 100));
+		assert_eq!(table.value(state, Action::Stop), 0.0);
+	}
+
+	#[test]
+	fn update_moves_value_toward_reward_when_next_state_is_unvisited() {
+		let mut table = QTable::default();
+		let state = State::relative(Point::new(0, 0), Point::new(100, 0));
+		let next_state = State::relative(Point::new(10, 0), Point::new(100, 0));
+
+		// next_state has no recorded values, so best_next is 0.0 and the
+		// update reduces to `ALPHA * reward`.
+		table.update(state, Action::Move(Direction::Right), 1.0, next_state);
+
+		let expected = ALPHA * 1.0;
+		assert!((table.value(state, Action::Move(Direction::Right)) - expected).abs() < 1e-6);
+	}
+
+	#[test]
+	fn best_action_prefers_the_highest_valued_action() {
+		let mut table = QTable::default();
+		let state = State::relative(Point::new(0, 0), Point::new(100, 0));
+		table.update(state, Action::Move(Direction::Right), 10.0, state);
+
+		assert_eq!(table.best_action(state), Action::Move(Direction::Right));
+	}
+}
@@ -0,0 +1,19 @@
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
+/// World resource wrapping a seedable generator so systems can draw
+/// randomness that is reproducible given the same seed, instead of reaching
+/// for `thread_rng()`.
+pub struct GameRng(pub SmallRng);
+
+impl GameRng {
+	pub fn seeded(seed: u64) -> Self {
+		GameRng(SmallRng::seed_from_u64(seed))
+	}
+}
+
+impl Default for GameRng {
+	fn default() -> Self {
+		GameRng::seeded(0)
+	}
+}
@@ -0,0 +1,67 @@
+use sdl2::event::Event;
+use sdl2::render::{Texture, WindowCanvas};
+use specs::prelude::*;
+
+/// What a scene wants to happen to the stack after handling an event or tick.
+pub enum Transition {
+	None,
+	Push(Box<dyn Scene>),
+	Pop,
+	Switch(Box<dyn Scene>),
+}
+
+/// A single screen of the application (menu, gameplay, a pause overlay, ...).
+/// Only the top of the stack receives input and ticks; rendering walks the
+/// whole stack bottom-to-top so overlay scenes draw over whatever is beneath.
+pub trait Scene {
+	fn handle_event(&mut self, world: &mut World, event: &Event) -> Transition;
+	fn update(&mut self, world: &mut World) -> Transition;
+	fn render(&mut self, world: &World, canvas: &mut WindowCanvas, textures: &[Texture]) -> Result<(), String>;
+}
+
+pub struct SceneStack {
+	scenes: Vec<Box<dyn Scene>>,
+}
+
+impl SceneStack {
+	pub fn new(initial: Box<dyn Scene>) -> Self {
+		SceneStack {scenes: vec![initial]}
+	}
+
+	/// Returns `false` once the last scene has popped and the game should quit.
+	pub fn handle_event(&mut self, world: &mut World, event: &Event) -> bool {
+		let transition = match self.scenes.last_mut() {
+			Some(scene) => scene.handle_event(world, event),
+			None => return false,
+		};
+		self.apply(transition)
+	}
+
+	pub fn update(&mut self, world: &mut World) -> bool {
+		let transition = match self.scenes.last_mut() {
+			Some(scene) => scene.update(world),
+			None => return false,
+		};
+		self.apply(transition)
+	}
+
+	pub fn render(&mut self, world: &World, canvas: &mut WindowCanvas, textures: &[Texture]) -> Result<(), String> {
+		for scene in self.scenes.iter_mut() {
+			scene.render(world, canvas, textures)?;
+		}
+		Ok(())
+	}
+
+	fn apply(&mut self, transition: Transition) -> bool {
+		match transition {
+			Transition::None => {},
+			Transition::Push(scene) => self.scenes.push(scene),
+			Transition::Pop => {self.scenes.pop();},
+			Transition::Switch(scene) => {
+				self.scenes.pop();
+				self.scenes.push(scene);
+			},
+		}
+		!self.scenes.is_empty()
+	}
+}
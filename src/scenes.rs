@@ -0,0 +1,154 @@
+use sdl2::event::Event;
+use sdl2::keyboard::Keycode;
+use sdl2::pixels::Color;
+use sdl2::render::{Texture, WindowCanvas};
+
+use specs::prelude::*;
+
+use crate::components::*;
+use crate::scene::{Scene, Transition};
+use crate::{ai, animator, collision, keybindings, keyboard, physics, renderer, sound};
+use crate::MovementCommand;
+
+/// Title screen. Enter starts a new game.
+pub struct Menu;
+
+impl Scene for Menu {
+	fn handle_event(&mut self, world: &mut World, event: &Event) -> Transition {
+		match event {
+			Event::KeyDown {keycode: Some(Keycode::Return), ..} => Transition::Switch(Box::new(Gameplay::new(world))),
+			_ => Transition::None,
+		}
+	}
+
+	fn update(&mut self, _world: &mut World) -> Transition {
+		Transition::None
+	}
+
+	fn render(&mut self, _world: &World, canvas: &mut WindowCanvas, _textures: &[Texture]) -> Result<(), String> {
+		canvas.set_draw_color(Color::RGB(20, 20, 20));
+		canvas.clear();
+		Ok(())
+	}
+}
+
+/// The running game: owns the systems that simulate it and never ticks them
+/// unless it is the scene on top of the stack.
+pub struct Gameplay {
+	dispatcher: Dispatcher<'static, 'static>,
+}
+
+impl Gameplay {
+	pub fn new(world: &mut World) -> Self {
+		let mut dispatcher = DispatcherBuilder::new()
+			.with(keyboard::Keyboard, "Keyboard", &[])
+			.with(ai::AI, "AI", &[])
+			.with(physics::Physics, "Physics", &["Keyboard", "AI"])
+			.with(collision::Collision, "Collision", &["Physics"])
+			.with(animator::Animator, "Animator", &["Keyboard", "AI"])
+			.with(sound::Playback, "Playback", &["Keyboard", "Collision"])
+			.build();
+		dispatcher.setup(&mut world.res);
+
+		// `Collision` despawns the player on death, and `Menu` switches back
+		// here on every new game, so reset the world whenever there isn't a
+		// player already (first game excepted, since `run_game` spawns it).
+		let player_alive = world.read_storage::<KeyboardControlled>().join().next().is_some();
+		if !player_alive {
+			world.delete_all();
+			crate::spawn_player_and_enemies(world);
+		}
+
+		Gameplay {dispatcher}
+	}
+}
+
+impl Scene for Gameplay {
+	fn handle_event(&mut self, world: &mut World, event: &Event) -> Transition {
+		if let Event::KeyDown {keycode: Some(Keycode::Escape), repeat: false, ..} = event {
+			return Transition::Push(Box::new(Pause));
+		}
+
+		let command = {
+			let keybindings = world.read_resource::<keybindings::Keybindings>();
+			match event {
+				Event::KeyDown {keycode: Some(keycode), repeat: false, ..} => keybindings.get(keycode).cloned(),
+				Event::KeyUp {keycode: Some(keycode), repeat: false, ..} => {
+					if keybindings.contains_key(keycode) {
+						Some(MovementCommand::Stop)
+					} else {
+						None
+					}
+				},
+				_ => None,
+			}
+		};
+
+		if let Some(command) = command {
+			*world.write_resource() = Some(command);
+		}
+
+		Transition::None
+	}
+
+	fn update(&mut self, world: &mut World) -> Transition {
+		self.dispatcher.dispatch(&mut world.res);
+		world.maintain();
+
+		let player_alive = world.read_storage::<KeyboardControlled>().join().next().is_some();
+		if player_alive {
+			Transition::None
+		} else {
+			Transition::Switch(Box::new(GameOver))
+		}
+	}
+
+	fn render(&mut self, world: &World, canvas: &mut WindowCanvas, textures: &[Texture]) -> Result<(), String> {
+		renderer::render(canvas, Color::RGB(64, 64, 192), textures, world.system_data())
+	}
+}
+
+/// A transparent overlay on top of a suspended `Gameplay`. Escape resumes.
+pub struct Pause;
+
+impl Scene for Pause {
+	fn handle_event(&mut self, _world: &mut World, event: &Event) -> Transition {
+		match event {
+			Event::KeyDown {keycode: Some(Keycode::Escape), repeat: false, ..} => Transition::Pop,
+			_ => Transition::None,
+		}
+	}
+
+	fn update(&mut self, _world: &mut World) -> Transition {
+		Transition::None
+	}
+
+	fn render(&mut self, _world: &World, canvas: &mut WindowCanvas, _textures: &[Texture]) -> Result<(), String> {
+		canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+		canvas.set_draw_color(Color::RGBA(0, 0, 0, 160));
+		canvas.fill_rect(None)?;
+		Ok(())
+	}
+}
+
+/// Shown once the player's `Health` runs out. Enter returns to the menu.
+pub struct GameOver;
+
+impl Scene for GameOver {
+	fn handle_event(&mut self, _world: &mut World, event: &Event) -> Transition {
+		match event {
+			Event::KeyDown {keycode: Some(Keycode::Return), ..} => Transition::Switch(Box::new(Menu)),
+			_ => Transition::None,
+		}
+	}
+
+	fn update(&mut self, _world: &mut World) -> Transition {
+		Transition::None
+	}
+
+	fn render(&mut self, _world: &World, canvas: &mut WindowCanvas, _textures: &[Texture]) -> Result<(), String> {
+		canvas.set_draw_color(Color::RGB(80, 0, 0));
+		canvas.clear();
+		Ok(())
+	}
+}
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use sdl2::mixer::{self, Channel, Chunk, Sdl2MixerContext, AUDIO_S16LSB, DEFAULT_CHANNELS};
+use specs::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundId {
+	Footstep,
+	Impact,
+}
+
+/// Named clips loaded once at startup; looked up by `SoundId` at playback time.
+pub struct SoundBank(pub HashMap<SoundId, Chunk>);
+
+/// Events gameplay systems push onto during a dispatch; drained by `Playback`
+/// at the end of the same dispatch so deciding a sound should play stays
+/// separate from actually playing it.
+#[derive(Default)]
+pub struct SoundQueue(pub Vec<SoundId>);
+
+pub fn init() -> Result<Sdl2MixerContext, String> {
+	mixer::open_audio(44_100, AUDIO_S16LSB, DEFAULT_CHANNELS, 1_024)?;
+	let context = mixer::init(mixer::InitFlag::OGG)?;
+	mixer::allocate_channels(8);
+	Ok(context)
+}
+
+pub fn load_bank() -> Result<SoundBank, String> {
+	let mut clips = HashMap::new();
+	clips.insert(SoundId::Footstep, Chunk::from_file("assets/sfx/footstep.ogg")?);
+	clips.insert(SoundId::Impact, Chunk::from_file("assets/sfx/impact.ogg")?);
+	Ok(SoundBank(clips))
+}
+
+/// Pops every `SoundId` queued this dispatch and plays it on a free channel.
+pub struct Playback;
+
+impl<'a> System<'a> for Playback {
+	type SystemData = (Write<'a, SoundQueue>, ReadExpect<'a, SoundBank>);
+
+	fn run(&mut self, (mut queue, bank): Self::SystemData) {
+		for sound in queue.0.drain(..) {
+			if let Some(chunk) = bank.0.get(&sound) {
+				let _ = Channel::all().play(chunk, 0);
+			}
+		}
+	}
+}